@@ -2,6 +2,8 @@
 
 use crate::prelude::*;
 use bevy::ecs::query::WorldQuery;
+use parry2d::{na::DVector, shape::SharedShape as SharedShape2d};
+use parry3d::{na::DMatrix, shape::SharedShape as SharedShape3d};
 use std::ops::{AddAssign, SubAssign};
 
 #[derive(WorldQuery)]
@@ -16,6 +18,54 @@ impl<'w> IsRigidBodyItem<'w> {
     }
 }
 
+/// Linear velocity damping. [`RigidBodyQuery2dItem::apply_damping`]/[`RigidBodyQuery3dItem::apply_damping`]
+/// scale the [`LinearVelocity2d`]/[`LinearVelocity3d`] of the body by `1.0 / (1.0 + dt * damping)`,
+/// the stable implicit form of exponential decay.
+///
+/// This is useful for things like air drag or water resistance. The component does not damp the
+/// body by itself: `apply_damping` is called only by [`integrate_velocities_2d`]/[`integrate_velocities_3d`],
+/// and no plugin adds those systems to a schedule.
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, Deref, DerefMut, PartialEq)]
+#[reflect(Component)]
+pub struct LinearDamping(pub Scalar);
+
+/// Angular velocity damping. [`RigidBodyQuery2dItem::apply_damping`]/[`RigidBodyQuery3dItem::apply_damping`]
+/// scale the [`AngularVelocity2d`]/[`AngularVelocity3d`] of the body by `1.0 / (1.0 + dt * damping)`,
+/// the stable implicit form of exponential decay.
+///
+/// This is useful for things like air drag or water resistance. The component does not damp the
+/// body by itself: `apply_damping` is called only by [`integrate_velocities_2d`]/[`integrate_velocities_3d`],
+/// and no plugin adds those systems to a schedule.
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, Deref, DerefMut, PartialEq)]
+#[reflect(Component)]
+pub struct AngularDamping(pub Scalar);
+
+/// Marker component selecting velocity-based kinematic integration for a kinematic
+/// [`RigidBody2d`]/[`RigidBody3d`]: the user sets `linear_velocity`/`angular_velocity` directly,
+/// and [`integrate_kinematic`](RigidBodyQuery2dItem::integrate_kinematic) advances
+/// `position`/`rotation` from them, the same as a dynamic body.
+///
+/// Without this marker, a kinematic body is position-based: the user sets `position`/`rotation`
+/// directly, and `integrate_kinematic` derives velocity from the motion between the previous and
+/// current position instead, so that contacts transfer the correct relative velocity to the
+/// dynamic bodies it pushes. Both kinds keep the infinite
+/// [`dominance`](RigidBodyQuery2dItem::dominance) of non-dynamic bodies, so they are never pushed
+/// back by what they push.
+///
+/// `integrate_kinematic` is called only by [`integrate_velocities_2d`]/[`integrate_velocities_3d`],
+/// and no plugin adds those systems to a schedule.
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct KinematicVelocityBased;
+
+/// The substep delta time: a full step's `Time::delta_seconds()` divided by the solver's substep
+/// count. [`integrate_velocities_2d`]/[`integrate_velocities_3d`] pass it as `dt` to
+/// [`RigidBodyQuery2dItem::apply_damping`] and the kinematic integration methods instead of reading
+/// [`Time`] directly. No plugin inserts or updates this resource.
+#[derive(Reflect, Resource, Clone, Copy, Debug, Default, Deref, DerefMut, PartialEq)]
+#[reflect(Resource)]
+pub struct SubDeltaTime(pub Scalar);
+
 /// A [`WorldQuery`] to make querying and modifying rigid bodies more convenient.
 #[derive(WorldQuery)]
 #[world_query(mutable)]
@@ -40,6 +90,9 @@ pub struct RigidBodyQuery2d {
     pub restitution: &'static Restitution,
     pub locked_axes: Option<&'static LockedAxes2d>,
     pub dominance: Option<&'static Dominance>,
+    pub linear_damping: Option<&'static LinearDamping>,
+    pub angular_damping: Option<&'static AngularDamping>,
+    pub kinematic_velocity_based: Has<KinematicVelocityBased>,
 }
 
 impl<'w> RigidBodyQuery2dItem<'w> {
@@ -82,6 +135,52 @@ impl<'w> RigidBodyQuery2dItem<'w> {
             self.dominance.map_or(0, |dominance| dominance.0)
         }
     }
+
+    /// Applies [`LinearDamping`] and [`AngularDamping`] to the body's velocities, scaling them
+    /// by the stable implicit damping factor `1.0 / (1.0 + dt * damping)`. This should run once
+    /// per substep, before the velocities reach the solver.
+    pub fn apply_damping(&mut self, dt: Scalar) {
+        if let Some(damping) = self.linear_damping {
+            self.linear_velocity.0 *= 1.0 / (1.0 + dt * damping.0);
+        }
+        if let Some(damping) = self.angular_damping {
+            self.angular_velocity.0 *= 1.0 / (1.0 + dt * damping.0);
+        }
+    }
+
+    /// Integrates the position of a velocity-based kinematic body (see [`KinematicVelocityBased`])
+    /// from its `linear_velocity` and `angular_velocity`, the same way a dynamic body's position is
+    /// integrated. Velocity-based kinematic bodies set their velocity directly, so the position is
+    /// derived from it rather than the other way around.
+    pub fn integrate_kinematic_velocity(&mut self, dt: Scalar) {
+        self.accumulated_translation.0 += self.linear_velocity.0 * dt;
+        *self.rotation += Rotation2d::from_radians(self.angular_velocity.0 * dt);
+    }
+
+    /// Derives the velocity of a position-based kinematic body (one without
+    /// [`KinematicVelocityBased`]) from the motion between its previous and current
+    /// position/rotation. Position-based kinematic bodies set their position directly, so a
+    /// velocity has to be reconstructed from that motion for contacts to transfer the correct
+    /// relative velocity to dynamic bodies they push.
+    pub fn derive_kinematic_velocity(&mut self, dt: Scalar) {
+        self.linear_velocity.0 = (self.current_position() - self.previous_position.0) / dt;
+        self.angular_velocity.0 = (*self.rotation - *self.previous_rotation).as_radians() / dt;
+    }
+
+    /// Updates velocity/position coupling for a kinematic body each step: advances position from
+    /// velocity if it's [`KinematicVelocityBased`], or derives velocity from the position delta
+    /// otherwise. Does nothing for dynamic or static bodies. `dominance()` already treats both
+    /// kinematic kinds identically (infinite), so only this integration step needs to branch on
+    /// the kind.
+    pub fn integrate_kinematic(&mut self, dt: Scalar) {
+        if self.rb.is_kinematic() {
+            if self.kinematic_velocity_based {
+                self.integrate_kinematic_velocity(dt);
+            } else {
+                self.derive_kinematic_velocity(dt);
+            }
+        }
+    }
 }
 
 /// A [`WorldQuery`] to make querying and modifying rigid bodies more convenient.
@@ -108,6 +207,9 @@ pub struct RigidBodyQuery3d {
     pub restitution: &'static Restitution,
     pub locked_axes: Option<&'static LockedAxes3d>,
     pub dominance: Option<&'static Dominance>,
+    pub linear_damping: Option<&'static LinearDamping>,
+    pub angular_damping: Option<&'static AngularDamping>,
+    pub kinematic_velocity_based: Has<KinematicVelocityBased>,
 }
 
 impl<'w> RigidBodyQuery3dItem<'w> {
@@ -150,6 +252,85 @@ impl<'w> RigidBodyQuery3dItem<'w> {
             self.dominance.map_or(0, |dominance| dominance.0)
         }
     }
+
+    /// Applies [`LinearDamping`] and [`AngularDamping`] to the body's velocities, scaling them
+    /// by the stable implicit damping factor `1.0 / (1.0 + dt * damping)`. This should run once
+    /// per substep, before the velocities reach the solver.
+    pub fn apply_damping(&mut self, dt: Scalar) {
+        if let Some(damping) = self.linear_damping {
+            self.linear_velocity.0 *= 1.0 / (1.0 + dt * damping.0);
+        }
+        if let Some(damping) = self.angular_damping {
+            self.angular_velocity.0 *= 1.0 / (1.0 + dt * damping.0);
+        }
+    }
+
+    /// Integrates the position of a velocity-based kinematic body (see [`KinematicVelocityBased`])
+    /// from its `linear_velocity` and `angular_velocity`, the same way a dynamic body's position is
+    /// integrated. Velocity-based kinematic bodies set their velocity directly, so the position is
+    /// derived from it rather than the other way around.
+    pub fn integrate_kinematic_velocity(&mut self, dt: Scalar) {
+        self.accumulated_translation.0 += self.linear_velocity.0 * dt;
+
+        let scaled_axis = self.angular_velocity.0 * dt;
+        let delta_rotation = Quaternion::from_scaled_axis(scaled_axis);
+        self.rotation.0 = (delta_rotation * self.rotation.0).normalize();
+    }
+
+    /// Derives the velocity of a position-based kinematic body (one without
+    /// [`KinematicVelocityBased`]) from the motion between its previous and current
+    /// position/rotation. Position-based kinematic bodies set their position directly, so a
+    /// velocity has to be reconstructed from that motion for contacts to transfer the correct
+    /// relative velocity to dynamic bodies they push.
+    pub fn derive_kinematic_velocity(&mut self, dt: Scalar) {
+        self.linear_velocity.0 = (self.current_position() - self.previous_position.0) / dt;
+
+        let delta_rotation = self.rotation.0 * self.previous_rotation.0.inverse();
+        self.angular_velocity.0 = if delta_rotation.w < 0.0 {
+            -2.0 * delta_rotation.xyz() / dt
+        } else {
+            2.0 * delta_rotation.xyz() / dt
+        };
+    }
+
+    /// Updates velocity/position coupling for a kinematic body each step: advances position from
+    /// velocity if it's [`KinematicVelocityBased`], or derives velocity from the position delta
+    /// otherwise. Does nothing for dynamic or static bodies. `dominance()` already treats both
+    /// kinematic kinds identically (infinite), so only this integration step needs to branch on
+    /// the kind.
+    pub fn integrate_kinematic(&mut self, dt: Scalar) {
+        if self.rb.is_kinematic() {
+            if self.kinematic_velocity_based {
+                self.integrate_kinematic_velocity(dt);
+            } else {
+                self.derive_kinematic_velocity(dt);
+            }
+        }
+    }
+}
+
+/// The velocity-integration stage for every 2D rigid body: applies
+/// [`LinearDamping`]/[`AngularDamping`] (see [`RigidBodyQuery2dItem::apply_damping`]), then
+/// integrates or derives velocity for kinematic bodies (see
+/// [`RigidBodyQuery2dItem::integrate_kinematic`]), using [`SubDeltaTime`] as `dt`. No plugin adds
+/// this system to a schedule.
+pub(crate) fn integrate_velocities_2d(mut bodies: Query<RigidBodyQuery2d>, sub_dt: Res<SubDeltaTime>) {
+    for mut body in &mut bodies {
+        body.apply_damping(sub_dt.0);
+        body.integrate_kinematic(sub_dt.0);
+    }
+}
+
+/// The velocity-integration stage for every 3D rigid body: applies
+/// [`LinearDamping`]/[`AngularDamping`] (see [`RigidBodyQuery3dItem::apply_damping`]), then
+/// integrates or derives velocity for kinematic bodies (see
+/// [`RigidBodyQuery3dItem::integrate_kinematic`]), using [`SubDeltaTime`] as `dt`. No plugin adds
+/// this system to a schedule.
+pub(crate) fn integrate_velocities_3d(mut bodies: Query<RigidBodyQuery3d>, sub_dt: Res<SubDeltaTime>) {
+    for mut body in &mut bodies {
+        body.apply_damping(sub_dt.0);
+        body.integrate_kinematic(sub_dt.0);
+    }
 }
 
 #[derive(WorldQuery)]
@@ -172,12 +353,188 @@ pub(crate) struct MassPropertiesQuery3d {
     pub center_of_mass: &'static mut CenterOfMass3d,
 }
 
+/// Makes a collider behave like a one-way platform: contacts are only solved when the contact
+/// normal points roughly along `allowed_normal`, letting bodies pass through from the other side
+/// (e.g. jumping up through a platform and later landing on it).
+///
+/// A contact is considered pass-through, and its normal impulse is skipped, when the dot product
+/// of the contact normal and `allowed_normal` falls below `threshold`.
+///
+/// This test is applied by [`filter_one_way_contact_2d`] and [`solve_contact_normal_impulse_2d`];
+/// the contact solver calls neither, so the component does not change how contacts are solved.
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct OneWayCollision2d {
+    pub allowed_normal: Vector2,
+    pub threshold: Scalar,
+}
+
+impl OneWayCollision2d {
+    /// Returns `true` if a contact with the given `normal` should be solved normally,
+    /// and `false` if its normal impulse should be skipped to let the body pass through.
+    pub fn allows_contact(&self, normal: Vector2) -> bool {
+        normal.dot(self.allowed_normal) >= self.threshold
+    }
+}
+
+/// Makes a collider behave like a one-way platform: contacts are only solved when the contact
+/// normal points roughly along `allowed_normal`, letting bodies pass through from the other side
+/// (e.g. jumping up through a platform and later landing on it).
+///
+/// A contact is considered pass-through, and its normal impulse is skipped, when the dot product
+/// of the contact normal and `allowed_normal` falls below `threshold`.
+///
+/// This test is applied by [`filter_one_way_contact_3d`] and [`solve_contact_normal_impulse_3d`];
+/// the contact solver calls neither, so the component does not change how contacts are solved.
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct OneWayCollision3d {
+    pub allowed_normal: Vector3,
+    pub threshold: Scalar,
+}
+
+impl OneWayCollision3d {
+    /// Returns `true` if a contact with the given `normal` should be solved normally,
+    /// and `false` if its normal impulse should be skipped to let the body pass through.
+    pub fn allows_contact(&self, normal: Vector3) -> bool {
+        normal.dot(self.allowed_normal) >= self.threshold
+    }
+}
+
+impl Collider2d {
+    /// Creates a heightfield collider from a row of `heights`, following parry's `HeightField`
+    /// convention where the samples span a total horizontal extent of `scale.x` (not `scale.x`
+    /// per sample), scaled vertically by `scale.y`. Lets large terrain statics be built without
+    /// tessellating them into thousands of segment colliders.
+    ///
+    /// Insert a [`Heightfield2d`] alongside the returned collider so
+    /// [`update_heightfield_collider_2d`] can (re)compute its AABB and mass properties.
+    pub fn heightfield(heights: Vec<Scalar>, scale: Vector2) -> Self {
+        let na_scale = parry2d::na::Vector2::new(scale.x, scale.y);
+        Self::from(SharedShape2d::heightfield(DVector::from_vec(heights), na_scale))
+    }
+}
+
+impl Collider3d {
+    /// Creates a heightfield collider from a row-major `rows x columns` grid of `heights`,
+    /// following parry's `HeightField` convention where the samples span a total footprint of
+    /// `scale.x` by `scale.z` (not `scale.x`/`scale.z` per sample), scaled vertically by
+    /// `scale.y`. Lets large terrain statics be built without tessellating them into thousands of
+    /// triangle colliders.
+    ///
+    /// Insert a [`Heightfield3d`] alongside the returned collider so
+    /// [`update_heightfield_collider_3d`] can (re)compute its AABB and mass properties.
+    pub fn heightfield(heights: Vec<Scalar>, rows: usize, columns: usize, scale: Vector3) -> Self {
+        let na_scale = parry3d::na::Vector3::new(scale.x, scale.y, scale.z);
+        Self::from(SharedShape3d::heightfield(
+            DMatrix::from_row_slice(rows, columns, &heights),
+            na_scale,
+        ))
+    }
+}
+
+/// Stores the sampled heights and scale a [`Collider2d::heightfield`] was built from, so
+/// [`update_heightfield_collider_2d`] can (re)compute its [`ColliderAabb2d`] and
+/// [`ColliderMassProperties2d`] without re-sampling the collider shape itself.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct Heightfield2d {
+    pub heights: Vec<Scalar>,
+    pub scale: Vector2,
+}
+
+/// Stores the sampled heights and scale a [`Collider3d::heightfield`] was built from, so
+/// [`update_heightfield_collider_3d`] can (re)compute its [`ColliderAabb3d`] and
+/// [`ColliderMassProperties3d`] without re-sampling the collider shape itself.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct Heightfield3d {
+    pub heights: Vec<Scalar>,
+    pub scale: Vector3,
+}
+
+/// Computes the [`ColliderAabb2d`] of a heightfield collider directly from its sampled `heights`,
+/// rather than from a tessellated mesh. Following parry's `HeightField` convention, `scale.x` is
+/// the horizontal span the samples are stretched across (not the spacing between them), so the
+/// footprint's half-width is just `scale.x / 2.0` regardless of sample count.
+pub(crate) fn heightfield_aabb_2d(heights: &[Scalar], scale: Vector2) -> ColliderAabb2d {
+    let min_height = heights.iter().copied().fold(Scalar::MAX, Scalar::min);
+    let max_height = heights.iter().copied().fold(Scalar::MIN, Scalar::max);
+
+    ColliderAabb2d::from_min_max(
+        Vector2::new(-scale.x / 2.0, min_height * scale.y),
+        Vector2::new(scale.x / 2.0, max_height * scale.y),
+    )
+}
+
+/// Computes the [`ColliderAabb3d`] of a heightfield collider directly from its sampled `heights`,
+/// rather than from a tessellated mesh. Following parry's `HeightField` convention, `scale.x`/
+/// `scale.z` are the horizontal footprint the samples are stretched across (not the spacing
+/// between them), so the footprint's half-extents are just `scale.x / 2.0` and `scale.z / 2.0`
+/// regardless of the grid's `rows x columns`.
+pub(crate) fn heightfield_aabb_3d(heights: &[Scalar], scale: Vector3) -> ColliderAabb3d {
+    let min_height = heights.iter().copied().fold(Scalar::MAX, Scalar::min);
+    let max_height = heights.iter().copied().fold(Scalar::MIN, Scalar::max);
+
+    ColliderAabb3d::from_min_max(
+        Vector3::new(-scale.x / 2.0, min_height * scale.y, -scale.z / 2.0),
+        Vector3::new(scale.x / 2.0, max_height * scale.y, scale.z / 2.0),
+    )
+}
+
+/// Heightfields are massless terrain statics, like other non-solid concave shapes, so they never
+/// contribute mass to the body. `MassPropertiesQuery2dItem`'s `AddAssign`/`SubAssign` stay
+/// consistent when this is summed in, since a zero-mass contribution is a no-op.
+pub(crate) fn heightfield_mass_properties_2d() -> ColliderMassProperties2d {
+    ColliderMassProperties2d::default()
+}
+
+/// Heightfields are massless terrain statics, like other non-solid concave shapes, so they never
+/// contribute mass to the body. `MassPropertiesQuery3dItem`'s `AddAssign`/`SubAssign` stay
+/// consistent when this is summed in, since a zero-mass contribution is a no-op.
+pub(crate) fn heightfield_mass_properties_3d() -> ColliderMassProperties3d {
+    ColliderMassProperties3d::default()
+}
+
+/// Recomputes [`ColliderAabb2d`] and [`ColliderMassProperties2d`] for every [`Heightfield2d`]
+/// collider from its stored heights/scale, via [`heightfield_aabb_2d`] and
+/// [`heightfield_mass_properties_2d`]. Heightfields are static terrain, so this only needs to run
+/// when a [`Heightfield2d`] is added or changed, unlike the per-shape AABB/mass update systems
+/// that run every step for moving colliders.
+pub(crate) fn update_heightfield_collider_2d(
+    mut colliders: Query<
+        (&Heightfield2d, &mut ColliderAabb2d, &mut ColliderMassProperties2d),
+        Changed<Heightfield2d>,
+    >,
+) {
+    for (heightfield, mut aabb, mut mass_properties) in &mut colliders {
+        *aabb = heightfield_aabb_2d(&heightfield.heights, heightfield.scale);
+        *mass_properties = heightfield_mass_properties_2d();
+    }
+}
+
+/// Recomputes [`ColliderAabb3d`] and [`ColliderMassProperties3d`] for every [`Heightfield3d`]
+/// collider from its stored heights/scale, via [`heightfield_aabb_3d`] and
+/// [`heightfield_mass_properties_3d`]. Heightfields are static terrain, so this only needs to run
+/// when a [`Heightfield3d`] is added or changed, unlike the per-shape AABB/mass update systems
+/// that run every step for moving colliders.
+pub(crate) fn update_heightfield_collider_3d(
+    mut colliders: Query<
+        (&Heightfield3d, &mut ColliderAabb3d, &mut ColliderMassProperties3d),
+        Changed<Heightfield3d>,
+    >,
+) {
+    for (heightfield, mut aabb, mut mass_properties) in &mut colliders {
+        *aabb = heightfield_aabb_3d(&heightfield.heights, heightfield.scale);
+        *mass_properties = heightfield_mass_properties_3d();
+    }
+}
+
 #[derive(WorldQuery)]
 #[world_query(mutable)]
 pub(crate) struct ColliderQuery2d {
     pub collider: &'static mut Collider2d,
     pub aabb: &'static mut ColliderAabb2d,
     pub mass_properties: &'static mut ColliderMassProperties2d,
+    pub one_way_collision: Option<&'static OneWayCollision2d>,
 }
 
 #[derive(WorldQuery)]
@@ -186,6 +543,77 @@ pub(crate) struct ColliderQuery3d {
     pub collider: &'static mut Collider3d,
     pub aabb: &'static mut ColliderAabb3d,
     pub mass_properties: &'static mut ColliderMassProperties3d,
+    pub one_way_collision: Option<&'static OneWayCollision3d>,
+}
+
+/// The contact-filtering stage for [`OneWayCollision2d`]: given a manifold's contact `normal`
+/// (pointing from `collider1` to `collider2`), returns whether a normal constraint should be
+/// generated for it: `false` when either collider's [`OneWayCollision2d`] rejects the normal
+/// (`collider2` is tested against `-normal`). Its only caller is
+/// [`solve_contact_normal_impulse_2d`], which the contact solver does not call.
+pub(crate) fn filter_one_way_contact_2d(
+    normal: Vector2,
+    collider1: &ColliderQuery2dItem,
+    collider2: &ColliderQuery2dItem,
+) -> bool {
+    let allows1 = collider1
+        .one_way_collision
+        .map_or(true, |one_way| one_way.allows_contact(normal));
+    let allows2 = collider2
+        .one_way_collision
+        .map_or(true, |one_way| one_way.allows_contact(-normal));
+    allows1 && allows2
+}
+
+/// Resolves the normal impulse the solver should apply for a single contact point between
+/// `collider1` and `collider2`: `candidate_impulse` when [`filter_one_way_contact_2d`] allows the
+/// contact, or `0.0` otherwise. No constraint-generation code calls this function.
+pub(crate) fn solve_contact_normal_impulse_2d(
+    normal: Vector2,
+    candidate_impulse: Scalar,
+    collider1: &ColliderQuery2dItem,
+    collider2: &ColliderQuery2dItem,
+) -> Scalar {
+    if filter_one_way_contact_2d(normal, collider1, collider2) {
+        candidate_impulse
+    } else {
+        0.0
+    }
+}
+
+/// The contact-filtering stage for [`OneWayCollision3d`]: given a manifold's contact `normal`
+/// (pointing from `collider1` to `collider2`), returns whether a normal constraint should be
+/// generated for it: `false` when either collider's [`OneWayCollision3d`] rejects the normal
+/// (`collider2` is tested against `-normal`). Its only caller is
+/// [`solve_contact_normal_impulse_3d`], which the contact solver does not call.
+pub(crate) fn filter_one_way_contact_3d(
+    normal: Vector3,
+    collider1: &ColliderQuery3dItem,
+    collider2: &ColliderQuery3dItem,
+) -> bool {
+    let allows1 = collider1
+        .one_way_collision
+        .map_or(true, |one_way| one_way.allows_contact(normal));
+    let allows2 = collider2
+        .one_way_collision
+        .map_or(true, |one_way| one_way.allows_contact(-normal));
+    allows1 && allows2
+}
+
+/// Resolves the normal impulse the solver should apply for a single contact point between
+/// `collider1` and `collider2`: `candidate_impulse` when [`filter_one_way_contact_3d`] allows the
+/// contact, or `0.0` otherwise. No constraint-generation code calls this function.
+pub(crate) fn solve_contact_normal_impulse_3d(
+    normal: Vector3,
+    candidate_impulse: Scalar,
+    collider1: &ColliderQuery3dItem,
+    collider2: &ColliderQuery3dItem,
+) -> Scalar {
+    if filter_one_way_contact_3d(normal, collider1, collider2) {
+        candidate_impulse
+    } else {
+        0.0
+    }
 }
 
 impl<'w> AddAssign<ColliderMassProperties2d> for MassPropertiesQuery2dItem<'w> {
@@ -244,7 +672,43 @@ impl<'w> SubAssign<ColliderMassProperties2d> for MassPropertiesQuery2dItem<'w> {
 }
 
 impl<'w> AddAssign<ColliderMassProperties3d> for MassPropertiesQuery3dItem<'w> {
+    /// Equivalent to [`Self::add_rotated`] with an identity `local_frame`, for a child collider
+    /// whose axes already line up with the body's.
     fn add_assign(&mut self, rhs: ColliderMassProperties3d) {
+        self.add_rotated(rhs, Rotation3d::default());
+    }
+}
+
+impl<'w> SubAssign<ColliderMassProperties3d> for MassPropertiesQuery3dItem<'w> {
+    /// Equivalent to [`Self::sub_rotated`] with an identity `local_frame`, for a child collider
+    /// whose axes already line up with the body's.
+    fn sub_assign(&mut self, rhs: ColliderMassProperties3d) {
+        self.sub_rotated(rhs, Rotation3d::default());
+    }
+}
+
+impl Inertia3d {
+    /// Rotates the inertia tensor by `rotation`, i.e. computes `R * I * Rᵀ`.
+    ///
+    /// Used to express a child collider's inertia, given in its own local frame, in its parent
+    /// body's frame before combining mass properties with [`MassPropertiesQuery3dItem::add_rotated`]
+    /// or [`MassPropertiesQuery3dItem::sub_rotated`].
+    pub fn rotated(&self, rotation: &Rotation3d) -> Self {
+        let r = Matrix3::from_quat(rotation.0);
+        Self(r * self.0 * r.transpose())
+    }
+}
+
+impl<'w> MassPropertiesQuery3dItem<'w> {
+    /// Adds a child collider's mass properties to this body's combined mass properties, rotating
+    /// the child's inertia by `local_frame` (its orientation relative to the body) before applying
+    /// the parallel-axis shift. Plain `AddAssign` calls this with an identity `local_frame`; call
+    /// this directly instead when the child collider in a compound shape has its own rotation.
+    ///
+    /// [`Inertia3d`] already stores the full 3x3 tensor rather than a principal-diagonal plus a
+    /// separate frame, so rotating the child's tensor into the body's frame and summing is exact
+    /// on its own — there's no compact principal representation to re-diagonalize back into.
+    pub fn add_rotated(&mut self, rhs: ColliderMassProperties3d, local_frame: Rotation3d) {
         let new_mass = self.mass.0 + rhs.mass.0;
 
         if new_mass <= 0.0 {
@@ -254,23 +718,26 @@ impl<'w> AddAssign<ColliderMassProperties3d> for MassPropertiesQuery3dItem<'w> {
         let com1 = self.center_of_mass.0;
         let com2 = rhs.center_of_mass.0;
 
-        // Compute the combined center of mass and combined inertia tensor
         let new_com = (com1 * self.mass.0 + com2 * rhs.mass.0) / new_mass;
         let i1 = self.inertia.shifted(self.mass.0, new_com - com1);
-        let i2 = rhs.inertia.shifted(rhs.mass.0, new_com - com2);
+        let i2 = rhs
+            .inertia
+            .rotated(&local_frame)
+            .shifted(rhs.mass.0, new_com - com2);
         let new_inertia = i1 + i2;
 
-        // Update mass properties
         self.mass.0 = new_mass;
         self.inverse_mass.0 = 1.0 / self.mass.0;
         self.inertia.0 = new_inertia;
         self.inverse_inertia.0 = self.inertia.inverse().0;
         self.center_of_mass.0 = new_com;
     }
-}
 
-impl<'w> SubAssign<ColliderMassProperties3d> for MassPropertiesQuery3dItem<'w> {
-    fn sub_assign(&mut self, rhs: ColliderMassProperties3d) {
+    /// Subtracts a child collider's mass properties from this body's combined mass properties,
+    /// rotating the child's inertia by `local_frame` (its orientation relative to the body) before
+    /// applying the parallel-axis shift. Plain `SubAssign` calls this with an identity
+    /// `local_frame`; see [`Self::add_rotated`] for when to call this directly instead.
+    pub fn sub_rotated(&mut self, rhs: ColliderMassProperties3d, local_frame: Rotation3d) {
         if self.mass.0 + rhs.mass.0 <= 0.0 {
             return;
         }
@@ -279,17 +746,18 @@ impl<'w> SubAssign<ColliderMassProperties3d> for MassPropertiesQuery3dItem<'w> {
         let com1 = self.center_of_mass.0;
         let com2 = rhs.center_of_mass.0;
 
-        // Compute the combined center of mass and combined inertia tensor
         let new_com = if new_mass > Scalar::EPSILON {
             (com1 * self.mass.0 - com2 * rhs.mass.0) / new_mass
         } else {
             com1
         };
         let i1 = self.inertia.shifted(self.mass.0, new_com - com1);
-        let i2 = rhs.inertia.shifted(rhs.mass.0, new_com - com2);
+        let i2 = rhs
+            .inertia
+            .rotated(&local_frame)
+            .shifted(rhs.mass.0, new_com - com2);
         let new_inertia = i1 - i2;
 
-        // Update mass properties
         self.mass.0 = new_mass;
         self.inverse_mass.0 = 1.0 / self.mass.0;
         self.inertia.0 = new_inertia;
@@ -433,4 +901,149 @@ mod tests {
             epsilon = 0.000_001
         );
     }
+
+    #[test]
+    fn heightfield_aabb_spans_total_scale_not_sample_spacing() {
+        let heights = vec![1.0, 3.0, -2.0, 0.0];
+        let aabb = heightfield_aabb_2d(&heights, Vector2::new(10.0, 2.0));
+
+        // Half-width is scale.x / 2, independent of heights.len(): parry's heightfield `scale` is
+        // the total span the samples are stretched across, not the spacing between them.
+        assert_relative_eq!(aabb.min, Vector2::new(-5.0, -4.0));
+        assert_relative_eq!(aabb.max, Vector2::new(5.0, 6.0));
+    }
+
+    #[test]
+    fn mass_properties_add_rotated_swaps_principal_axes() {
+        // Create app
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+
+        // Spawn a body with no mass properties yet, so the combined result is just the rotated
+        // child (isolates the rotation from the parallel-axis shift, since both centers of mass
+        // are the origin).
+        app.world.spawn(MassProperties3dBundle {
+            mass: Mass(0.0),
+            inverse_mass: InverseMass(0.0),
+            center_of_mass: CenterOfMass3d(Vector3::ZERO),
+            ..default()
+        });
+
+        let collider_mass_props = ColliderMassProperties3d {
+            mass: Mass(2.0),
+            inverse_mass: InverseMass(0.5),
+            inertia: Inertia3d(Matrix3::from_diagonal(Vector3::new(1.0, 2.0, 3.0))),
+            center_of_mass: CenterOfMass3d(Vector3::ZERO),
+            ..default()
+        };
+
+        // A 90-degree rotation about Z permutes the principal axes: x and y swap, z is unchanged.
+        let local_frame = Rotation3d(Quaternion::from_rotation_z(std::f64::consts::FRAC_PI_2 as Scalar));
+
+        let mut query = app.world.query::<MassPropertiesQuery3d>();
+        let mut mass_props = query.single_mut(&mut app.world);
+        mass_props.add_rotated(collider_mass_props, local_frame);
+
+        // (reference values were calculated by hand)
+        assert_relative_eq!(
+            mass_props.inertia.0,
+            Matrix3::from_diagonal(Vector3::new(2.0, 1.0, 3.0)),
+            epsilon = 0.000_001
+        );
+    }
+
+    #[test]
+    fn apply_damping_scales_velocity_by_implicit_factor() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let entity = spawn_kinematic_2d(&mut app, true);
+        app.world
+            .entity_mut(entity)
+            .insert((LinearDamping(2.0), AngularDamping(4.0)));
+
+        let mut query = app.world.query::<RigidBodyQuery2d>();
+        let mut body = query.single_mut(&mut app.world);
+        body.apply_damping(0.5);
+
+        // (reference values were calculated by hand: 1.0 / (1.0 + dt * damping))
+        assert_relative_eq!(body.linear_velocity.0, Vector2::X * 2.0 / 2.0);
+        assert_relative_eq!(body.angular_velocity.0, 1.0 / 3.0, epsilon = 0.000_001);
+    }
+
+    fn spawn_kinematic_2d(app: &mut App, velocity_based: bool) -> Entity {
+        let entity = app
+            .world
+            .spawn((
+                (
+                    RigidBody2d::Kinematic,
+                    Position2d(Vector2::X * 3.0),
+                    Rotation2d::from_radians(0.0),
+                    PreviousPosition2d(Vector2::ZERO),
+                    PreviousRotation2d(Rotation2d::from_radians(0.0)),
+                    AccumulatedTranslation2d(Vector2::ZERO),
+                    LinearVelocity2d(Vector2::X * 2.0),
+                    PreSolveLinearVelocity2d(Vector2::ZERO),
+                    AngularVelocity2d(1.0),
+                ),
+                (
+                    PreSolveAngularVelocity2d(0.0),
+                    Mass(1.0),
+                    InverseMass(1.0),
+                    Inertia2d(1.0),
+                    InverseInertia2d(1.0),
+                    CenterOfMass2d(Vector2::ZERO),
+                    Friction::default(),
+                    Restitution::default(),
+                ),
+            ))
+            .id();
+
+        if velocity_based {
+            app.world.entity_mut(entity).insert(KinematicVelocityBased);
+        }
+
+        entity
+    }
+
+    #[test]
+    fn kinematic_velocity_based_integrates_position_from_velocity() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        spawn_kinematic_2d(&mut app, true);
+
+        let mut query = app.world.query::<RigidBodyQuery2d>();
+        let mut body = query.single_mut(&mut app.world);
+        body.integrate_kinematic(0.5);
+
+        // (reference values were calculated by hand)
+        assert_relative_eq!(body.accumulated_translation.0, Vector2::X * 1.0);
+        assert_relative_eq!(body.rotation.as_radians(), 0.5, epsilon = 0.000_001);
+    }
+
+    #[test]
+    fn one_way_collision_allows_contact_above_threshold_only() {
+        let one_way = OneWayCollision2d {
+            allowed_normal: Vector2::Y,
+            threshold: 0.9,
+        };
+
+        assert!(one_way.allows_contact(Vector2::Y));
+        assert!(!one_way.allows_contact(Vector2::NEG_Y));
+        assert!(!one_way.allows_contact(Vector2::X));
+    }
+
+    #[test]
+    fn kinematic_position_based_derives_velocity_from_motion() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        spawn_kinematic_2d(&mut app, false);
+
+        let mut query = app.world.query::<RigidBodyQuery2d>();
+        let mut body = query.single_mut(&mut app.world);
+        // current_position() == position + accumulated_translation == 3.0 + 0.0
+        body.integrate_kinematic(0.5);
+
+        // (reference values were calculated by hand)
+        assert_relative_eq!(body.linear_velocity.0, Vector2::X * 6.0);
+    }
 }